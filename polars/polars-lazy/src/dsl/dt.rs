@@ -1,7 +1,5 @@
-use polars_time::prelude::TemporalMethods;
-
 use super::*;
-use crate::prelude::function_expr::TemporalFunction;
+use crate::prelude::function_expr::{Ambiguous, TemporalFunction};
 
 /// Specialized expressions for [`Series`] with dates/datetimes.
 pub struct DateLikeNameSpace(pub(crate) Expr);
@@ -9,12 +7,17 @@ pub struct DateLikeNameSpace(pub(crate) Expr);
 impl DateLikeNameSpace {
     /// Format Date/datetime with a formatting rule
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
-    pub fn strftime(self, fmt: &str) -> Expr {
+    ///
+    /// An optional `locale` (e.g. `"fr_FR"`) controls which language `%B`,
+    /// `%b`, `%A`, `%a` and `%p` render in; unknown locales fall back to
+    /// English rather than erroring.
+    pub fn strftime(self, fmt: &str, locale: Option<&str>) -> Expr {
         let fmt = fmt.to_string();
-        let function = move |s: Series| s.strftime(&fmt);
+        let locale = locale.map(|s| s.to_string());
         self.0
-            .map(function, GetOutput::from_type(DataType::Utf8))
-            .with_fmt("strftime")
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Strftime(
+                fmt, locale,
+            )))
     }
 
     /// Change the underlying [`TimeUnit`]. And update the data accordingly.
@@ -82,6 +85,26 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Convert to another time zone, keeping the physical instant fixed so
+    /// that the displayed wall time (e.g. through [`DateLikeNameSpace::strftime`])
+    /// is that of `tz`, accounting for DST at each individual timestamp.
+    /// Unlike [`DateLikeNameSpace::with_time_zone`], `tz` must be a known
+    /// IANA time zone.
+    pub fn convert_time_zone(self, tz: TimeZone) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::ConvertTimeZone(tz)))
+    }
+
+    /// Localize to `tz`: interpret the existing naive wall time as already
+    /// being in `tz`, without changing the wall-clock value. `ambiguous`
+    /// decides how DST fall-back (ambiguous) local times are resolved;
+    /// spring-forward (nonexistent) local times always raise.
+    pub fn replace_time_zone(self, tz: Option<TimeZone>, ambiguous: Ambiguous) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::ReplaceTimeZone(tz, ambiguous),
+        ))
+    }
+
     /// Get the year of a Date/Datetime
     pub fn year(self) -> Expr {
         self.0
@@ -153,6 +176,22 @@ impl DateLikeNameSpace {
         self.0
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Second))
     }
+    /// Get the millisecond part of the underlying sub-second, in 0..1000.
+    /// Unlike dividing [`DateLikeNameSpace::nanosecond`] by hand, this works
+    /// regardless of the series' [`TimeUnit`].
+    pub fn millisecond(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MilliSecond))
+    }
+
+    /// Get the microsecond part of the underlying sub-second, in 0..1_000_000.
+    /// Unlike dividing [`DateLikeNameSpace::nanosecond`] by hand, this works
+    /// regardless of the series' [`TimeUnit`].
+    pub fn microsecond(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::MicroSecond))
+    }
+
     /// Get the nanosecond of a Time64
     pub fn nanosecond(self) -> Expr {
         self.0
@@ -170,4 +209,22 @@ impl DateLikeNameSpace {
     pub fn offset_by(self, by: Duration) -> Expr {
         self.0.map_private(FunctionExpr::DateOffset(by))
     }
+
+    /// Divide the date/datetime range into buckets of width `every` and map
+    /// each value to the start of the bucket it falls in, then shift the
+    /// result by `offset`. Calendar-aware (month/year) widths are snapped
+    /// using civil-date arithmetic, since a month is not a fixed duration.
+    pub fn truncate(self, every: Duration, offset: Duration) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Truncate(
+                every, offset,
+            )))
+    }
+
+    /// Divide the date/datetime range into buckets of width `every` and map
+    /// each value to its nearest bucket boundary.
+    pub fn round(self, every: Duration) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Round(every)))
+    }
 }
@@ -0,0 +1,554 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use polars_time::prelude::*;
+
+use super::temporal::{datetime_to_timestamp, timestamp_to_datetime};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum TemporalFunction {
+    Year,
+    IsoYear,
+    Quarter,
+    Month,
+    Week,
+    WeekDay,
+    Day,
+    OrdinalDay,
+    Hour,
+    Minute,
+    Second,
+    MilliSecond,
+    MicroSecond,
+    NanoSecond,
+    TimeStamp(TimeUnit),
+    Truncate(Duration, Duration),
+    Round(Duration),
+    Strftime(String, Option<String>),
+    ConvertTimeZone(TimeZone),
+    ReplaceTimeZone(Option<TimeZone>, Ambiguous),
+}
+
+/// Policy for resolving a local datetime that the target time zone's DST
+/// fall-back makes ambiguous. Local datetimes that a spring-forward gap makes
+/// impossible always raise, whatever the policy.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum Ambiguous {
+    Earliest,
+    Latest,
+    Raise,
+}
+
+impl Display for TemporalFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use TemporalFunction::*;
+        let s = match self {
+            Year => "year",
+            IsoYear => "iso_year",
+            Quarter => "quarter",
+            Month => "month",
+            Week => "week",
+            WeekDay => "weekday",
+            Day => "day",
+            OrdinalDay => "ordinal_day",
+            Hour => "hour",
+            Minute => "minute",
+            Second => "second",
+            MilliSecond => "millisecond",
+            MicroSecond => "microsecond",
+            NanoSecond => "nanosecond",
+            TimeStamp(_) => "timestamp",
+            Truncate(..) => "truncate",
+            Round(_) => "round",
+            Strftime(..) => "strftime",
+            ConvertTimeZone(_) => "convert_time_zone",
+            ReplaceTimeZone(..) => "replace_time_zone",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Look up a locale identifier (e.g. `"fr_FR"`) for use with
+/// [`chrono::NaiveDateTime::format_localized`] and
+/// `parse_from_str_localized`. Returns `None` for anything not in this
+/// table; callers fall back to English in that case, see [`strftime`].
+///
+/// Requires chrono's `unstable-locales` Cargo feature.
+pub(super) fn locale_from_str(name: &str) -> Option<chrono::Locale> {
+    use chrono::Locale::*;
+    Some(match name {
+        "en_US" => en_US,
+        "fr_FR" => fr_FR,
+        "de_DE" => de_DE,
+        "es_ES" => es_ES,
+        "it_IT" => it_IT,
+        "pt_BR" => pt_BR,
+        "nl_NL" => nl_NL,
+        "ja_JP" => ja_JP,
+        "zh_CN" => zh_CN,
+        _ => return None,
+    })
+}
+
+/// Format a Date/Datetime `Series` as a string `Series`. When `locale` is
+/// `None` or unrecognized, month/weekday/am-pm names are rendered in
+/// English rather than erroring.
+pub(super) fn strftime(s: &Series, fmt: &str, locale: Option<&str>) -> PolarsResult<Series> {
+    let locale = locale
+        .and_then(locale_from_str)
+        .unwrap_or(chrono::Locale::en_US);
+    match s.dtype() {
+        DataType::Date => {
+            let ca = s.date().unwrap();
+            Ok(ca
+                .as_date_iter()
+                .map(|opt| opt.map(|d| d.format_localized(fmt, locale).to_string()))
+                .collect::<Utf8Chunked>()
+                .with_name(s.name())
+                .into_series())
+        }
+        DataType::Datetime(tu, tz) => {
+            let tu = *tu;
+            let ca = s.datetime().unwrap();
+            match tz {
+                // The physical value is always the UTC instant; `tz` only
+                // labels how it should be displayed (same convention as
+                // `convert_time_zone`), so resolve the local wall-clock
+                // time for `tz` before formatting instead of formatting
+                // the raw UTC digits.
+                Some(tz_str) => {
+                    use chrono::TimeZone as _;
+                    let zone = parse_tz(tz_str)?;
+                    let out: Utf8Chunked = ca
+                        .0
+                        .iter()
+                        .map(|opt_v| {
+                            opt_v.map(|v| {
+                                let utc_ndt = timestamp_to_datetime(v, tu);
+                                let local = chrono::Utc
+                                    .from_utc_datetime(&utc_ndt)
+                                    .with_timezone(&zone)
+                                    .naive_local();
+                                local.format_localized(fmt, locale).to_string()
+                            })
+                        })
+                        .collect();
+                    Ok(out.with_name(s.name()).into_series())
+                }
+                None => Ok(ca
+                    .as_datetime_iter()
+                    .map(|opt| opt.map(|dt| dt.format_localized(fmt, locale).to_string()))
+                    .collect::<Utf8Chunked>()
+                    .with_name(s.name())
+                    .into_series()),
+            }
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`strftime` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+pub(super) fn year(s: &Series) -> PolarsResult<Series> {
+    s.year().map(|ca| ca.into_series())
+}
+
+pub(super) fn iso_year(s: &Series) -> PolarsResult<Series> {
+    s.iso_year().map(|ca| ca.into_series())
+}
+
+pub(super) fn month(s: &Series) -> PolarsResult<Series> {
+    s.month().map(|ca| ca.into_series())
+}
+
+pub(super) fn quarter(s: &Series) -> PolarsResult<Series> {
+    s.quarter().map(|ca| ca.into_series())
+}
+
+pub(super) fn week(s: &Series) -> PolarsResult<Series> {
+    s.week().map(|ca| ca.into_series())
+}
+
+pub(super) fn weekday(s: &Series) -> PolarsResult<Series> {
+    s.weekday().map(|ca| ca.into_series())
+}
+
+pub(super) fn day(s: &Series) -> PolarsResult<Series> {
+    s.day().map(|ca| ca.into_series())
+}
+
+pub(super) fn ordinal_day(s: &Series) -> PolarsResult<Series> {
+    s.ordinal_day().map(|ca| ca.into_series())
+}
+
+pub(super) fn hour(s: &Series) -> PolarsResult<Series> {
+    s.hour().map(|ca| ca.into_series())
+}
+
+pub(super) fn minute(s: &Series) -> PolarsResult<Series> {
+    s.minute().map(|ca| ca.into_series())
+}
+
+pub(super) fn second(s: &Series) -> PolarsResult<Series> {
+    s.second().map(|ca| ca.into_series())
+}
+
+pub(super) fn nanosecond(s: &Series) -> PolarsResult<Series> {
+    s.nanosecond().map(|ca| ca.into_series())
+}
+
+/// The sub-second part of the timestamp expressed in milliseconds (0-999),
+/// independent of the series' own [`TimeUnit`].
+pub(super) fn millisecond(s: &Series) -> PolarsResult<Series> {
+    s.nanosecond().map(|ca| (&ca / 1_000_000).into_series())
+}
+
+/// The sub-second part of the timestamp expressed in microseconds
+/// (0-999_999), independent of the series' own [`TimeUnit`].
+pub(super) fn microsecond(s: &Series) -> PolarsResult<Series> {
+    s.nanosecond().map(|ca| (&ca / 1_000).into_series())
+}
+
+pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
+    s.timestamp(tu).map(|ca| ca.into_series())
+}
+
+fn tu_scale(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// Snap `ndt`'s month down to the closest lower multiple of `every_months`
+/// months, counted from month 0000-01 (for windows of 1, 2, 3, 4, 6 or 12
+/// months this gives the same boundaries as counting from 1970-01).
+fn floor_month_window(ndt: chrono::NaiveDateTime, every_months: i64) -> chrono::NaiveDateTime {
+    use chrono::Datelike;
+    let month_idx = ndt.year() as i64 * 12 + ndt.month() as i64 - 1;
+    let floored_idx = month_idx - month_idx.rem_euclid(every_months);
+    let year = floored_idx.div_euclid(12) as i32;
+    let month = (floored_idx.rem_euclid(12) + 1) as u32;
+    chrono::NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn truncate_impl(ca: &Int64Chunked, every: &Duration, offset: &Duration, tu: TimeUnit) -> Int64Chunked {
+    // `offset` can itself mix months and a fixed ns part (same as `every`),
+    // so it's shifted with `add_duration` — the same month/ns decomposition
+    // `offset_by` already uses — rather than only ever reading its ns part.
+    if every.months() != 0 {
+        // `every` decomposes into a month part (snapped via civil-date
+        // arithmetic, since a month isn't a fixed number of nanoseconds)
+        // and a fixed nanosecond part, which is folded the same way.
+        let every_ns = every.duration_ns() / tu_scale(tu);
+        ca.apply(|t| {
+            let ndt = timestamp_to_datetime(t, tu);
+            let floored = floor_month_window(ndt, every.months());
+            let with_every_ns = timestamp_to_datetime(datetime_to_timestamp(floored, tu) + every_ns, tu);
+            datetime_to_timestamp(super::temporal::add_duration(with_every_ns, offset), tu)
+        })
+    } else {
+        let d = every.duration_ns() / tu_scale(tu);
+        ca.apply(|t| {
+            let floored = timestamp_to_datetime(t - t.rem_euclid(d), tu);
+            datetime_to_timestamp(super::temporal::add_duration(floored, offset), tu)
+        })
+    }
+}
+
+fn round_impl(ca: &Int64Chunked, every: &Duration, tu: TimeUnit) -> Int64Chunked {
+    if every.months() != 0 {
+        // Same month/nanosecond decomposition as `truncate_impl`: fold the
+        // fixed nanosecond part of `every` into both window boundaries.
+        let every_ns = every.duration_ns() / tu_scale(tu);
+        ca.apply(|t| {
+            let ndt = timestamp_to_datetime(t, tu);
+            let lower = floor_month_window(ndt, every.months());
+            let upper = floor_month_window(
+                super::temporal::shift_months(ndt, every.months()),
+                every.months(),
+            );
+            let lower_ts = datetime_to_timestamp(lower, tu) + every_ns;
+            let upper_ts = datetime_to_timestamp(upper, tu) + every_ns;
+            if t - lower_ts >= upper_ts - t {
+                upper_ts
+            } else {
+                lower_ts
+            }
+        })
+    } else {
+        let d = every.duration_ns() / tu_scale(tu);
+        ca.apply(|t| {
+            let floor = t - t.rem_euclid(d);
+            if t.rem_euclid(d) * 2 >= d {
+                floor + d
+            } else {
+                floor
+            }
+        })
+    }
+}
+
+/// Divide the timeline into windows of `every` and map each value to the
+/// start of the window it falls in, shifted by `offset`.
+pub(super) fn truncate(s: &Series, every: Duration, offset: Duration) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(tu, tz) => {
+            let tu = *tu;
+            let tz = tz.clone();
+            let ca = s.datetime().unwrap();
+            let out = truncate_impl(&ca.0, &every, &offset, tu);
+            Ok(out.into_datetime(tu, tz).into_series())
+        }
+        DataType::Date => {
+            let ca = s.date().unwrap();
+            let phys: Int64Chunked = ca.0.apply(|v| v as i64 * 86_400_000);
+            let out = truncate_impl(&phys, &every, &offset, TimeUnit::Milliseconds);
+            Ok(out
+                // floor, not truncate-toward-zero: `/` would round a
+                // pre-epoch, non-exact-day millisecond value up by a day.
+                .apply(|v| v.div_euclid(86_400_000) as i32)
+                .into_date()
+                .into_series())
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`truncate` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+/// Divide the timeline into windows of `every` and map each value to the
+/// nearest window boundary.
+pub(super) fn round(s: &Series, every: Duration) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(tu, tz) => {
+            let tu = *tu;
+            let tz = tz.clone();
+            let ca = s.datetime().unwrap();
+            let out = round_impl(&ca.0, &every, tu);
+            Ok(out.into_datetime(tu, tz).into_series())
+        }
+        DataType::Date => {
+            let ca = s.date().unwrap();
+            let phys: Int64Chunked = ca.0.apply(|v| v as i64 * 86_400_000);
+            let out = round_impl(&phys, &every, TimeUnit::Milliseconds);
+            Ok(out
+                // floor, not truncate-toward-zero: see `truncate`'s Date arm.
+                .apply(|v| v.div_euclid(86_400_000) as i32)
+                .into_date()
+                .into_series())
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`round` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+fn resolve_local(
+    tz: &chrono_tz::Tz,
+    ndt: chrono::NaiveDateTime,
+    ambiguous: Ambiguous,
+) -> PolarsResult<chrono::NaiveDateTime> {
+    use chrono::{LocalResult, TimeZone};
+    match tz.from_local_datetime(&ndt) {
+        LocalResult::Single(dt) => Ok(dt.naive_utc()),
+        LocalResult::None => Err(PolarsError::ComputeError(
+            format!("non-existent local time {} in time zone {}", ndt, tz).into(),
+        )),
+        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+            Ambiguous::Earliest => Ok(earliest.naive_utc()),
+            Ambiguous::Latest => Ok(latest.naive_utc()),
+            Ambiguous::Raise => Err(PolarsError::ComputeError(
+                format!(
+                    "ambiguous local time {} in time zone {} (DST fall-back); \
+                     pick an `ambiguous` policy to resolve it",
+                    ndt, tz
+                )
+                .into(),
+            )),
+        },
+    }
+}
+
+/// Look up `tz` in the IANA time zone database.
+///
+/// Requires the `chrono-tz` crate.
+fn parse_tz(tz: &str) -> PolarsResult<chrono_tz::Tz> {
+    tz.parse()
+        .map_err(|_| PolarsError::ComputeError(format!("unknown time zone: {:?}", tz).into()))
+}
+
+/// Interpret the existing naive wall-clock time as belonging to `tz`
+/// (localization). Unlike [`convert_time_zone`], the physical instant is
+/// *not* preserved: this only attaches a time zone to an already-naive time.
+pub(super) fn replace_time_zone(
+    s: &Series,
+    tz: Option<TimeZone>,
+    ambiguous: Ambiguous,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(tu, _) => {
+            let tu = *tu;
+            let ca = s.datetime().unwrap();
+            match &tz {
+                Some(tz_str) => {
+                    let target = parse_tz(tz_str)?;
+                    let out: Int64Chunked = ca.0.try_apply(|v| {
+                        let ndt = timestamp_to_datetime(v, tu);
+                        let utc = resolve_local(&target, ndt, ambiguous)?;
+                        Ok(datetime_to_timestamp(utc, tu))
+                    })?;
+                    Ok(out.into_datetime(tu, tz).into_series())
+                }
+                None => Ok(ca.0.clone().into_datetime(tu, None).into_series()),
+            }
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`replace_time_zone` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+/// Keep the physical instant fixed and label it with `tz`, so the displayed
+/// wall time (see [`strftime`]) is that of `tz`, with each timestamp's UTC
+/// offset resolved individually so DST transitions are handled correctly.
+pub(super) fn convert_time_zone(s: &Series, tz: TimeZone) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(tu, _) => {
+            let tu = *tu;
+            parse_tz(&tz)?;
+            let ca = s.datetime().unwrap();
+            // The physical value is already the UTC instant regardless of
+            // the series' current `tz` label (that label is purely a display
+            // hint, same convention as `hour()`/`day()`/etc. and `strftime`),
+            // so the values are kept as they are and only the label changes.
+            Ok(ca.0.clone().into_datetime(tu, Some(tz)).into_series())
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`convert_time_zone` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime_series(name: &str, timestamps_ms: &[i64], tz: Option<&str>) -> Series {
+        Int64Chunked::from_slice(name, timestamps_ms)
+            .into_datetime(TimeUnit::Milliseconds, tz.map(|s| s.to_string()))
+            .into_series()
+    }
+
+    fn get_ms(s: &Series) -> Option<i64> {
+        s.datetime().unwrap().0.get(0)
+    }
+
+    // 2021-06-01T16:00:00Z: noon EDT (America/New_York, UTC-4) / 17:00 BST
+    // (Europe/London, UTC+1).
+    const JUN_1_2021_16_UTC_MS: i64 = 1_622_563_200_000;
+
+    #[test]
+    fn truncate_floors_pre_epoch_timestamps_towards_negative_infinity() {
+        // 1969-12-31T23:30:00Z, half an hour before the epoch.
+        let s = datetime_series("a", &[-1_800_000], None);
+        let out = truncate(&s, Duration::parse("1h"), Duration::parse("0ns")).unwrap();
+        // must floor to 1969-12-31T23:00:00Z, not round toward zero.
+        assert_eq!(get_ms(&out), Some(-3_600_000));
+    }
+
+    #[test]
+    fn truncate_applies_a_calendar_month_offset() {
+        // 2021-01-15T00:00:00Z truncated to the day (a no-op here), then
+        // shifted by a 1-month offset -> 2021-02-15T00:00:00Z.
+        let s = datetime_series("a", &[1_610_668_800_000], None);
+        let out = truncate(&s, Duration::parse("1d"), Duration::parse("1mo")).unwrap();
+        assert_eq!(get_ms(&out), Some(1_613_347_200_000));
+    }
+
+    #[test]
+    fn truncate_and_round_snap_to_a_calendar_month_window() {
+        // 2021-03-20T12:00:00Z is closer to 2021-04-01 than to 2021-03-01.
+        let s = datetime_series("a", &[1_616_241_600_000], None);
+        let truncated = truncate(&s, Duration::parse("1mo"), Duration::parse("0ns")).unwrap();
+        assert_eq!(get_ms(&truncated), Some(1_614_556_800_000)); // 2021-03-01
+        let rounded = round(&s, Duration::parse("1mo")).unwrap();
+        assert_eq!(get_ms(&rounded), Some(1_617_235_200_000)); // 2021-04-01
+    }
+
+    #[test]
+    fn strftime_falls_back_to_english_for_unknown_locale() {
+        // 2021-06-01 is a Tuesday.
+        let s = datetime_series("a", &[JUN_1_2021_16_UTC_MS], None);
+        let out = strftime(&s, "%A", Some("xx_XX")).unwrap();
+        assert_eq!(out.utf8().unwrap().get(0), Some("Tuesday"));
+    }
+
+    #[test]
+    fn convert_time_zone_keeps_the_physical_instant() {
+        let s = datetime_series("a", &[JUN_1_2021_16_UTC_MS], Some("America/New_York"));
+        let out = convert_time_zone(&s, "Europe/London".to_string()).unwrap();
+        // the physical instant must stay unchanged: only the label differs.
+        assert_eq!(
+            out.datetime().unwrap().0.get(0),
+            Some(JUN_1_2021_16_UTC_MS)
+        );
+    }
+
+    #[test]
+    fn convert_time_zone_round_trips_through_non_utc_source() {
+        let s = datetime_series("a", &[JUN_1_2021_16_UTC_MS], Some("America/New_York"));
+        let to_london = convert_time_zone(&s, "Europe/London".to_string()).unwrap();
+        let back = convert_time_zone(&to_london, "America/New_York".to_string()).unwrap();
+        assert_eq!(
+            back.datetime().unwrap().0.get(0),
+            Some(JUN_1_2021_16_UTC_MS)
+        );
+    }
+
+    #[test]
+    fn replace_time_zone_localizes_without_shifting_wall_clock() {
+        // naive 2021-06-01T12:00:00, localized as America/New_York (EDT,
+        // UTC-4) -> physical instant becomes 2021-06-01T16:00:00Z.
+        const NOON_NAIVE_MS: i64 = 1_622_548_800_000;
+        let s = datetime_series("a", &[NOON_NAIVE_MS], None);
+        let out = replace_time_zone(&s, Some("America/New_York".to_string()), Ambiguous::Raise)
+            .unwrap();
+        assert_eq!(
+            out.datetime().unwrap().0.get(0),
+            Some(JUN_1_2021_16_UTC_MS)
+        );
+    }
+
+    #[test]
+    fn replace_time_zone_ambiguous_dst_fallback_policy() {
+        // 2021-11-07T01:30:00 local is ambiguous in America/New_York (DST
+        // fall-back at 02:00 EDT -> 01:00 EST).
+        const NAIVE_FALLBACK_MS: i64 = 1_636_248_600_000;
+        let s = datetime_series("a", &[NAIVE_FALLBACK_MS], None);
+
+        let earliest =
+            replace_time_zone(&s, Some("America/New_York".to_string()), Ambiguous::Earliest)
+                .unwrap();
+        let latest =
+            replace_time_zone(&s, Some("America/New_York".to_string()), Ambiguous::Latest)
+                .unwrap();
+        let earliest_ts = earliest.datetime().unwrap().0.get(0).unwrap();
+        let latest_ts = latest.datetime().unwrap().0.get(0).unwrap();
+        // the two resolutions are exactly one hour (the DST offset) apart.
+        assert_eq!(latest_ts - earliest_ts, 3_600_000);
+
+        let raised =
+            replace_time_zone(&s, Some("America/New_York".to_string()), Ambiguous::Raise);
+        assert!(raised.is_err());
+    }
+}
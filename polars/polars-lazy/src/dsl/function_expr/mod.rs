@@ -40,7 +40,7 @@ use polars_core::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "temporal")]
-pub(super) use self::datetime::TemporalFunction;
+pub(super) use self::datetime::{Ambiguous, TemporalFunction};
 pub(super) use self::nan::NanFunction;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
@@ -395,8 +395,17 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Hour => map!(datetime::hour),
             Minute => map!(datetime::minute),
             Second => map!(datetime::second),
+            MilliSecond => map!(datetime::millisecond),
+            MicroSecond => map!(datetime::microsecond),
             NanoSecond => map!(datetime::nanosecond),
             TimeStamp(tu) => map!(datetime::timestamp, tu),
+            Truncate(every, offset) => map!(datetime::truncate, every, offset),
+            Round(every) => map!(datetime::round, every),
+            Strftime(fmt, locale) => map!(datetime::strftime, &fmt, locale.as_deref()),
+            ConvertTimeZone(tz) => map!(datetime::convert_time_zone, tz.clone()),
+            ReplaceTimeZone(tz, ambiguous) => {
+                map!(datetime::replace_time_zone, tz.clone(), ambiguous)
+            }
         }
     }
 }
@@ -0,0 +1,352 @@
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum StringFunction {
+    Contains {
+        pat: String,
+        literal: bool,
+    },
+    EndsWith(String),
+    StartsWith(String),
+    Extract {
+        pat: String,
+        group_index: usize,
+    },
+    ExtractAll(String),
+    CountMatch(String),
+    #[cfg(feature = "string_justify")]
+    Zfill(usize),
+    #[cfg(feature = "string_justify")]
+    LJust {
+        width: usize,
+        fillchar: char,
+    },
+    #[cfg(feature = "string_justify")]
+    RJust {
+        width: usize,
+        fillchar: char,
+    },
+    #[cfg(feature = "temporal")]
+    Strptime(StrptimeOptions),
+    #[cfg(feature = "concat_str")]
+    ConcatVertical(String),
+    #[cfg(feature = "concat_str")]
+    ConcatHorizontal(String),
+    #[cfg(feature = "regex")]
+    Replace {
+        all: bool,
+        literal: bool,
+    },
+    Uppercase,
+    Lowercase,
+}
+
+impl Display for StringFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use StringFunction::*;
+        let s = match self {
+            Contains { .. } => "contains",
+            EndsWith(_) => "ends_with",
+            StartsWith(_) => "starts_with",
+            Extract { .. } => "extract",
+            ExtractAll(_) => "extract_all",
+            CountMatch(_) => "count_match",
+            #[cfg(feature = "string_justify")]
+            Zfill(_) => "zfill",
+            #[cfg(feature = "string_justify")]
+            LJust { .. } => "ljust",
+            #[cfg(feature = "string_justify")]
+            RJust { .. } => "rjust",
+            #[cfg(feature = "temporal")]
+            Strptime(_) => "strptime",
+            #[cfg(feature = "concat_str")]
+            ConcatVertical(_) => "concat_str",
+            #[cfg(feature = "concat_str")]
+            ConcatHorizontal(_) => "concat_str_horizontal",
+            #[cfg(feature = "regex")]
+            Replace { .. } => "replace",
+            Uppercase => "uppercase",
+            Lowercase => "lowercase",
+        };
+        write!(f, "str.{}", s)
+    }
+}
+
+/// Options for [`StringFunction::Strptime`].
+#[cfg(feature = "temporal")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct StrptimeOptions {
+    /// The dtype the string column should be parsed into.
+    pub date_dtype: DataType,
+    /// How to parse each value, see [`StrptimeFormat`].
+    pub format: StrptimeFormat,
+    /// If `true`, the first unparseable value raises; otherwise it becomes null.
+    pub strict: bool,
+    /// Not read by [`strptime`]: every value must match the format in full,
+    /// whether this is `true` or `false`; prefix matches are not accepted.
+    pub exact: bool,
+    /// Locale (e.g. `"fr_FR"`) used to parse localized `%B`/`%b`/`%A`/`%a`/`%p`
+    /// names. Falls back to English when `None` or unrecognized.
+    pub locale: Option<String>,
+}
+
+/// How [`StringFunction::Strptime`] should interpret each value.
+#[cfg(feature = "temporal")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum StrptimeFormat {
+    /// Try each chrono format string in order, keeping the first that parses.
+    Custom(Vec<String>),
+    /// RFC 2822, e.g. "Tue, 1 Jul 2003 10:52:37 +0200".
+    Rfc2822,
+    /// RFC 3339 / ISO-8601, tolerating a space instead of `T` as date/time
+    /// separator and a trailing numeric UTC offset (including `-00:00`).
+    Rfc3339,
+}
+
+pub(super) fn contains(s: &Series, pat: &str, literal: bool) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    if literal {
+        ca.contains_literal(pat).map(|ca| ca.into_series())
+    } else {
+        ca.contains(pat).map(|ca| ca.into_series())
+    }
+}
+
+pub(super) fn ends_with(s: &Series, sub: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.ends_with(sub).into_series())
+}
+
+pub(super) fn starts_with(s: &Series, sub: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.starts_with(sub).into_series())
+}
+
+pub(super) fn extract(s: &Series, pat: &str, group_index: usize) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.extract(pat, group_index).map(|ca| ca.into_series())
+}
+
+pub(super) fn extract_all(s: &Series, pat: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.extract_all(pat).map(|ca| ca.into_series())
+}
+
+pub(super) fn count_match(s: &Series, pat: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.count_match(pat).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_justify")]
+pub(super) fn zfill(s: &Series, alignment: usize) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.zfill(alignment).into_series())
+}
+
+#[cfg(feature = "string_justify")]
+pub(super) fn ljust(s: &Series, width: usize, fillchar: char) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.ljust(width, fillchar).into_series())
+}
+
+#[cfg(feature = "string_justify")]
+pub(super) fn rjust(s: &Series, width: usize, fillchar: char) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.rjust(width, fillchar).into_series())
+}
+
+#[cfg(feature = "concat_str")]
+pub(super) fn concat(s: &Series, delimiter: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(Series::new(s.name(), [ca.into_iter().fold(
+        String::new(),
+        |mut acc, v| {
+            if let Some(v) = v {
+                if !acc.is_empty() {
+                    acc.push_str(delimiter);
+                }
+                acc.push_str(v);
+            }
+            acc
+        },
+    )]))
+}
+
+#[cfg(feature = "concat_str")]
+pub(super) fn concat_hor(series: &[Series], delimiter: &str) -> PolarsResult<Series> {
+    polars_core::functions::concat_str(series, delimiter).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "regex")]
+pub(super) fn replace(s: &[Series], literal: bool, all: bool) -> PolarsResult<Series> {
+    let column = s[0].utf8()?;
+    let pat = s[1].utf8()?.get(0).ok_or_else(|| {
+        PolarsError::ComputeError("'pat' should have a single value".into())
+    })?;
+    let val = s[2].utf8()?.get(0).ok_or_else(|| {
+        PolarsError::ComputeError("'value' should have a single value".into())
+    })?;
+    let out = match (all, literal) {
+        (true, true) => column.replace_all_literal(pat, val),
+        (true, false) => column.replace_all(pat, val),
+        (false, true) => column.replace_literal(pat, val),
+        (false, false) => column.replace(pat, val),
+    };
+    out.map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "temporal")]
+pub(super) fn strptime(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
+    use super::datetime::locale_from_str;
+    use super::temporal::datetime_to_timestamp;
+
+    let ca = s.utf8()?;
+    let locale = options
+        .locale
+        .as_deref()
+        .and_then(locale_from_str)
+        .unwrap_or(chrono::Locale::en_US);
+
+    match &options.date_dtype {
+        DataType::Datetime(tu, tz) => {
+            let tu = *tu;
+            let out: Int64Chunked = ca.try_apply_on_opt(|opt_v| match opt_v {
+                None => Ok(None),
+                Some(v) => match parse_one(v, &options.format, locale) {
+                    Ok(ndt) => Ok(Some(datetime_to_timestamp(ndt, tu))),
+                    Err(_) if !options.strict => Ok(None),
+                    Err(e) => Err(PolarsError::ComputeError(
+                        format!("could not parse {:?} as {:?}: {}", v, options.format, e).into(),
+                    )),
+                },
+            })?;
+            Ok(out.into_datetime(tu, tz.clone()).into_series())
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("`strptime` not supported for dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+/// Try to parse a single value per `format`, returning the first candidate
+/// (for [`StrptimeFormat::Custom`]) that succeeds.
+#[cfg(feature = "temporal")]
+fn parse_one(
+    v: &str,
+    format: &StrptimeFormat,
+    locale: chrono::Locale,
+) -> Result<chrono::NaiveDateTime, String> {
+    match format {
+        StrptimeFormat::Rfc2822 => chrono::DateTime::parse_from_rfc2822(v)
+            .map(|dt| dt.naive_utc())
+            .map_err(|e| e.to_string()),
+        StrptimeFormat::Rfc3339 => {
+            parse_rfc3339(v).map(|dt| dt.naive_utc()).map_err(|e| e.to_string())
+        }
+        StrptimeFormat::Custom(fmts) => {
+            let mut last_err = "no candidate formats given".to_string();
+            for fmt in fmts {
+                match chrono::NaiveDateTime::parse_from_str_localized(v, fmt, locale) {
+                    Ok(ndt) => return Ok(ndt),
+                    Err(e) => {
+                        // a date-only format yields midnight of that date
+                        let mut parsed = chrono::format::Parsed::new();
+                        let items = chrono::format::StrftimeItems::new_with_locale(fmt, locale);
+                        if chrono::format::parse(&mut parsed, v, items).is_ok() {
+                            if let Ok(d) = parsed.to_naive_date() {
+                                return Ok(d.and_hms_opt(0, 0, 0).unwrap());
+                            }
+                        }
+                        last_err = e.to_string()
+                    }
+                }
+            }
+            Err(last_err)
+        }
+    }
+}
+
+/// As chrono does for round-tripping `%+`, accept either a space or a `T`
+/// between the date and time parts of an RFC 3339 / ISO-8601 string.
+#[cfg(feature = "temporal")]
+fn parse_rfc3339(v: &str) -> chrono::ParseResult<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(v)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(&v.replacen(' ', "T", 1)))
+}
+
+pub(super) fn uppercase(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.to_uppercase().into_series())
+}
+
+pub(super) fn lowercase(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.to_lowercase().into_series())
+}
+
+#[cfg(all(test, feature = "temporal"))]
+mod tests {
+    use super::*;
+
+    fn strptime_ms(format: StrptimeFormat, strict: bool, values: &[&str]) -> PolarsResult<Series> {
+        let s = Series::new("a", values);
+        let options = StrptimeOptions {
+            date_dtype: DataType::Datetime(TimeUnit::Milliseconds, None),
+            format,
+            strict,
+            exact: true,
+            locale: None,
+        };
+        strptime(&s, &options)
+    }
+
+    fn get_ms(s: &Series) -> Option<i64> {
+        s.datetime().unwrap().0.get(0)
+    }
+
+    #[test]
+    fn custom_formats_try_each_candidate_in_order_until_one_matches() {
+        let format = StrptimeFormat::Custom(vec!["%Y-%m-%d".to_string(), "%d/%m/%Y".to_string()]);
+        // the first candidate doesn't match this value; the second does.
+        let out = strptime_ms(format, true, &["31/01/2021"]).unwrap();
+        assert_eq!(get_ms(&out), Some(1_612_051_200_000)); // 2021-01-31T00:00:00Z
+    }
+
+    #[test]
+    fn non_strict_nulls_unparseable_values_instead_of_raising() {
+        let format = StrptimeFormat::Custom(vec!["%Y-%m-%d".to_string()]);
+        let out = strptime_ms(format, false, &["not a date"]).unwrap();
+        assert_eq!(get_ms(&out), None);
+    }
+
+    #[test]
+    fn strict_raises_on_the_first_unparseable_value() {
+        let format = StrptimeFormat::Custom(vec!["%Y-%m-%d".to_string()]);
+        assert!(strptime_ms(format, true, &["not a date"]).is_err());
+    }
+
+    #[test]
+    fn rfc3339_accepts_a_space_separator_and_a_negative_zero_offset() {
+        let out =
+            strptime_ms(StrptimeFormat::Rfc3339, true, &["2021-06-01 16:00:00-00:00"]).unwrap();
+        assert_eq!(get_ms(&out), Some(1_622_563_200_000));
+    }
+
+    #[test]
+    fn rfc2822_parses_a_standard_example() {
+        let out = strptime_ms(
+            StrptimeFormat::Rfc2822,
+            true,
+            &["Tue, 1 Jun 2021 16:00:00 +0000"],
+        )
+        .unwrap();
+        assert_eq!(get_ms(&out), Some(1_622_563_200_000));
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_time::prelude::*;
+
+/// Offset every element of `s` by `offset`, taking leap years/months into account.
+pub(super) fn date_offset(s: Series, offset: Duration) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Datetime(tu, tz) => {
+            let tu = *tu;
+            let tz = tz.clone();
+            let ca = s.datetime().unwrap();
+            let out: Int64Chunked = ca.0.try_apply(|v| {
+                let ndt = timestamp_to_datetime(v, tu);
+                Ok(datetime_to_timestamp(add_duration(ndt, &offset), tu))
+            })?;
+            Ok(out.into_datetime(tu, tz).into_series())
+        }
+        DataType::Date => {
+            let ca = s.date().unwrap();
+            let out: Int32Chunked = ca.0.try_apply(|v| {
+                let ndt = NaiveDate::from_num_days_from_ce_opt(v + 719_163)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap();
+                Ok(add_duration(ndt, &offset).num_days_from_ce() - 719_163)
+            })?;
+            Ok(out.into_date().into_series())
+        }
+        dt => Err(PolarsError::ComputeError(
+            format!("cannot use 'offset_by' on Series of dtype: {:?}", dt).into(),
+        )),
+    }
+}
+
+/// Shift `ndt` by `offset`. The month/week/day part is applied with civil-date
+/// arithmetic first (a month is not a fixed number of nanoseconds), then the
+/// fixed nanosecond remainder is added.
+pub(super) fn add_duration(ndt: NaiveDateTime, offset: &Duration) -> NaiveDateTime {
+    let shifted = if offset.months() != 0 {
+        shift_months(ndt, offset.months())
+    } else {
+        ndt
+    };
+    shifted + chrono::Duration::nanoseconds(offset.duration_ns())
+}
+
+/// Add/subtract whole calendar months, clamping the day-of-month to the
+/// length of the resulting month (e.g. Jan 31 + 1mo -> Feb 28/29).
+pub(super) fn shift_months(ndt: NaiveDateTime, n_months: i64) -> NaiveDateTime {
+    let months = ndt.year() as i64 * 12 + ndt.month() as i64 - 1 + n_months;
+    let year = months.div_euclid(12) as i32;
+    let month = (months.rem_euclid(12) + 1) as u32;
+    let day = ndt.day().min(days_in_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_time(ndt.time())
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+pub(super) fn timestamp_to_datetime(v: i64, tu: TimeUnit) -> NaiveDateTime {
+    let (secs, nsecs) = match tu {
+        TimeUnit::Nanoseconds => (v.div_euclid(1_000_000_000), v.rem_euclid(1_000_000_000)),
+        TimeUnit::Microseconds => (v.div_euclid(1_000_000), v.rem_euclid(1_000_000) * 1_000),
+        TimeUnit::Milliseconds => (v.div_euclid(1_000), v.rem_euclid(1_000) * 1_000_000),
+    };
+    NaiveDateTime::from_timestamp_opt(secs, nsecs as u32).unwrap()
+}
+
+pub(super) fn datetime_to_timestamp(ndt: NaiveDateTime, tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => ndt.timestamp() * 1_000_000_000 + ndt.timestamp_subsec_nanos() as i64,
+        TimeUnit::Microseconds => ndt.timestamp() * 1_000_000 + ndt.timestamp_subsec_micros() as i64,
+        TimeUnit::Milliseconds => ndt.timestamp() * 1_000 + ndt.timestamp_subsec_millis() as i64,
+    }
+}